@@ -12,6 +12,44 @@ use rayon::prelude::*;
 
 use crate::prelude::*;
 
+/// Controls how [`ListNameSpaceExtension::eval`] reacts to an expression failing on an
+/// individual list element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ListEvalErrors {
+    /// Fail the whole `eval` with an error raised by one of the failing rows (the previous, and
+    /// only, behavior). When several rows fail, which of their errors is returned is not defined.
+    #[default]
+    Raise,
+    /// Replace the failing element with `null` and keep evaluating the remaining rows.
+    NullOnError,
+    /// Like [`ListEvalErrors::NullOnError`], but also prints the error for each nulled row to
+    /// stderr so dirty rows aren't silently dropped.
+    NullOnErrorAnnotated,
+}
+
+#[cfg(feature = "list_eval")]
+impl ListEvalErrors {
+    /// Reacts to row `idx` failing with `e`, returning the error that should abort the whole
+    /// `eval` under [`ListEvalErrors::Raise`], or `None` if the row should just become null.
+    fn handle(self, idx: usize, e: PolarsError) -> Option<PolarsError> {
+        match self {
+            ListEvalErrors::Raise => Some(e),
+            ListEvalErrors::NullOnError => None,
+            ListEvalErrors::NullOnErrorAnnotated => {
+                eprintln!("arr.eval: row {idx} raised '{e}', replaced with null");
+                None
+            }
+        }
+    }
+}
+
+/// References the `idx`-th list column passed to [`ListNameSpaceExtension::eval_many`]: the
+/// receiver is `element(0)`, and each entry of `other` is `element(1)`, `element(2)`, ...
+#[cfg(feature = "list_eval")]
+pub fn element(idx: usize) -> Expr {
+    col(&idx.to_string())
+}
+
 pub trait IntoListNameSpace {
     fn into_list_name_space(self) -> ListNameSpace;
 }
@@ -25,7 +63,7 @@ impl IntoListNameSpace for ListNameSpace {
 pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
     /// Run any [`Expr`] on these lists elements
     #[cfg(feature = "list_eval")]
-    fn eval(self, expr: Expr, parallel: bool) -> Expr {
+    fn eval(self, expr: Expr, parallel: bool, on_error: ListEvalErrors) -> Expr {
         let this = self.into_list_name_space();
 
         use crate::physical_plan::exotic::prepare_expression_for_context;
@@ -65,19 +103,41 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
 
             let state = ExecutionState::new();
 
+            // Length-preserving, element-wise expressions don't need a per-row `DataFrame`:
+            // evaluate once over the flattened inner values and reuse the original offsets.
+            // This fast path evaluates the whole column in one go, so it can't localize a
+            // failure to the row(s) that caused it; only take it when errors are fatal anyway.
+            if on_error == ListEvalErrors::Raise && is_elementwise_length_preserving(&expr) {
+                let out = lst.apply_to_inner(&|inner| {
+                    let in_len = inner.len();
+                    let df = DataFrame::new_no_checks(vec![inner]);
+                    let out = phys_expr.evaluate(&df, &state)?;
+                    if out.len() != in_len {
+                        return Err(PolarsError::ComputeError(
+                            "expressions in 'arr.eval' that change length are not allowed in the fast path".into(),
+                        ));
+                    }
+                    Ok(out)
+                })?;
+                return Ok(out.into_series());
+            }
+
             let mut err = None;
             let mut ca: ListChunked = if parallel {
                 let m_err = Mutex::new(None);
                 let ca: ListChunked = lst
                     .par_iter()
-                    .map(|opt_s| {
+                    .enumerate()
+                    .map(|(idx, opt_s)| {
                         opt_s.and_then(|s| {
                             let df = DataFrame::new_no_checks(vec![s]);
                             let out = phys_expr.evaluate(&df, &state);
                             match out {
                                 Ok(s) => Some(s),
                                 Err(e) => {
-                                    *m_err.lock().unwrap() = Some(e);
+                                    if let Some(e) = on_error.handle(idx, e) {
+                                        *m_err.lock().unwrap() = Some(e);
+                                    }
                                     None
                                 }
                             }
@@ -90,7 +150,8 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
                 let mut df_container = DataFrame::new_no_checks(vec![]);
 
                 lst.into_iter()
-                    .map(|s| {
+                    .enumerate()
+                    .map(|(idx, s)| {
                         s.and_then(|s| {
                             df_container.get_columns_mut().push(s);
                             let out = phys_expr.evaluate(&df_container, &state);
@@ -98,7 +159,7 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
                             match out {
                                 Ok(s) => Some(s),
                                 Err(e) => {
-                                    err = Some(e);
+                                    err = on_error.handle(idx, e);
                                     None
                                 }
                             }
@@ -122,6 +183,191 @@ pub trait ListNameSpaceExtension: IntoListNameSpace + Sized {
             )
             .with_fmt("eval")
     }
+
+    /// Run any [`Expr`] element-wise over several lists at once, zipped by row.
+    ///
+    /// `element(0)`, `element(1)`, ... refer to the lists in this call's order, `self` being
+    /// `element(0)` and `other[i]` being `element(i + 1)`. All lists must have the same length
+    /// in every row.
+    #[cfg(feature = "list_eval")]
+    fn eval_many(self, other: Vec<Expr>, expr: Expr, parallel: bool) -> Expr {
+        let this = self.into_list_name_space();
+        let n_inputs = other.len() + 1;
+
+        use crate::physical_plan::exotic::prepare_expression_for_context;
+        use crate::physical_plan::state::ExecutionState;
+
+        let expr2 = expr.clone();
+        let func = move |s: &mut [Series]| {
+            for e in expr.into_iter() {
+                match e {
+                    #[cfg(feature = "dtype-categorical")]
+                    Expr::Cast {
+                        data_type: DataType::Categorical(_),
+                        ..
+                    } => {
+                        return Err(PolarsError::ComputeError(
+                            "Casting to 'Categorical' not allowed in 'arr.eval'".into(),
+                        ))
+                    }
+                    Expr::Column(name) => {
+                        if name.parse::<usize>().map(|i| i >= n_inputs).unwrap_or(true) {
+                            return Err(PolarsError::ComputeError(r#"Named columns not allowed in 'arr.eval'. Consider using 'element(i)'."#.into()));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let lists = s
+                .iter()
+                .map(|s| s.list())
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            let first = lists[0];
+            ensure_same_outer_length(&lists)?;
+            if first.is_empty() {
+                // ensure we get the new schema
+                let fields: Vec<Field> = lists.iter().map(|lst| lst.ref_field().clone()).collect();
+                let fld = field_to_dtype_many(&fields, &expr);
+                return Ok(Some(Series::new_empty(first.name(), fld.data_type())));
+            }
+
+            let schema: Schema = lists
+                .iter()
+                .enumerate()
+                .map(|(i, lst)| Field::new(&i.to_string(), lst.inner_dtype()))
+                .collect();
+            let phys_expr =
+                prepare_expression_for_context("", &expr, &schema, Context::Default)?;
+
+            let state = ExecutionState::new();
+
+            // fetch each row lazily and in lock-step, rather than collecting every column's
+            // rows up front: avoids doubling the per-row materialization the fast path in
+            // `eval` was written to get rid of.
+            let eval_row = |idx: usize| -> Option<PolarsResult<Series>> {
+                let opt_series = lists
+                    .iter()
+                    .map(|lst| lst.get(idx))
+                    .collect::<Option<Vec<_>>>()?;
+
+                let len = opt_series[0].len();
+                if opt_series.iter().any(|s| s.len() != len) {
+                    return Some(Err(PolarsError::ComputeError(
+                        "all lists in 'arr.eval_many' must have the same length per row".into(),
+                    )));
+                }
+
+                let columns = opt_series
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, mut s)| {
+                        s.rename(&i.to_string());
+                        s
+                    })
+                    .collect();
+                let df = DataFrame::new_no_checks(columns);
+                Some(phys_expr.evaluate(&df, &state))
+            };
+
+            let mut err = None;
+            let mut ca: ListChunked = if parallel {
+                let m_err = Mutex::new(None);
+                let ca: ListChunked = (0..first.len())
+                    .into_par_iter()
+                    .map(|idx| {
+                        eval_row(idx).and_then(|out| match out {
+                            Ok(s) => Some(s),
+                            Err(e) => {
+                                *m_err.lock().unwrap() = Some(e);
+                                None
+                            }
+                        })
+                    })
+                    .collect();
+                err = m_err.lock().unwrap().take();
+                ca
+            } else {
+                (0..first.len())
+                    .map(|idx| {
+                        eval_row(idx).and_then(|out| match out {
+                            Ok(s) => Some(s),
+                            Err(e) => {
+                                err = Some(e);
+                                None
+                            }
+                        })
+                    })
+                    .collect_trusted()
+            };
+
+            ca.rename(first.name());
+
+            match err {
+                None => Ok(Some(ca.into_series())),
+                Some(e) => Err(e),
+            }
+        };
+
+        this.0
+            .map_many(
+                func,
+                &other,
+                GetOutput::map_fields(move |fields| field_to_dtype_many(fields, &expr2)),
+            )
+            .with_fmt("eval_many")
+    }
+}
+
+/// Whether `expr` is safe to run once over the flattened inner values of a `ListChunked`
+/// instead of once per list row.
+///
+/// This has to be conservative: it's not enough for `expr` to preserve length, it must also
+/// never look across a list boundary. A `Function`/`AnonymousFunction` node can hide arbitrary
+/// order- or neighbor-sensitive behavior (`shift`, `diff`, `rolling_*`, `rank`, ...) that would
+/// silently leak values between rows if run once over the flattened values, and a post-hoc
+/// length check can't catch that since the output length still matches. Rather than deny-listing
+/// the ops that are known to look across rows, only allow those function nodes whose own
+/// `collect_groups` hint already says they're `ElementWise` (the same flag the query planner
+/// relies on elsewhere to know a function can't see past its own row), in addition to the node
+/// kinds that are trivially row-local: columns, literals, casts, aliases and binary/ternary
+/// combinations of those. The expression must also reference a column: one made only of
+/// literals yields a single value rather than one per element.
+#[cfg(feature = "list_eval")]
+fn is_elementwise_length_preserving(expr: &Expr) -> bool {
+    if !expr.into_iter().any(|e| matches!(e, Expr::Column(_))) {
+        return false;
+    }
+    expr.into_iter().all(|e| {
+        matches!(
+            e,
+            Expr::Column(_)
+                | Expr::Literal(_)
+                | Expr::Alias(_, _)
+                | Expr::Cast { .. }
+                | Expr::BinaryExpr { .. }
+                | Expr::Ternary { .. }
+        ) || matches!(
+            e,
+            Expr::Function { options, .. } | Expr::AnonymousFunction { options, .. }
+                if options.collect_groups == ApplyOptions::ElementWise
+        )
+    })
+}
+
+/// All list columns given to `eval_many` must have the same number of rows so they can be
+/// zipped by position; this check runs before the (row-)empty fast-out so a genuine height
+/// mismatch is always reported, even when the first column happens to be empty.
+#[cfg(feature = "list_eval")]
+fn ensure_same_outer_length(lists: &[&ListChunked]) -> PolarsResult<()> {
+    let len = lists[0].len();
+    if lists.iter().any(|lst| lst.len() != len) {
+        return Err(PolarsError::ComputeError(
+            "all list columns passed to 'arr.eval_many' must have the same number of rows".into(),
+        ));
+    }
+    Ok(())
 }
 
 #[cfg(feature = "list_eval")]
@@ -152,4 +398,201 @@ fn field_to_dtype(f: &Field, expr: &Expr) -> Field {
     }
 }
 
+/// Like [`field_to_dtype`], but for an expression evaluated over several zipped list columns at
+/// once: builds the dummy `DataFrame` with one empty column per input, named by its positional
+/// index, matching the schema `eval_many` prepares its physical expression against.
+#[cfg(feature = "list_eval")]
+fn field_to_dtype_many(fields: &[Field], expr: &Expr) -> Field {
+    let columns = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let dtype = f
+                .data_type()
+                .inner_dtype()
+                .cloned()
+                .unwrap_or_else(|| f.data_type().clone());
+            Series::new_empty(&i.to_string(), &dtype)
+        })
+        .collect();
+    let df = DataFrame::new_no_checks(columns);
+
+    #[cfg(feature = "python")]
+    let out = {
+        use pyo3::Python;
+        Python::with_gil(|py| py.allow_threads(|| df.lazy().select([expr.clone()]).collect()))
+    };
+    #[cfg(not(feature = "python"))]
+    let out = { df.lazy().select([expr.clone()]).collect() };
+
+    match out {
+        Ok(out) => {
+            let dtype = out.get_columns()[0].dtype();
+            Field::new(fields[0].name(), DataType::List(Box::new(dtype.clone())))
+        }
+        Err(_) => Field::new(fields[0].name(), DataType::Null),
+    }
+}
+
 impl ListNameSpaceExtension for ListNameSpace {}
+
+#[cfg(all(test, feature = "list_eval"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_elementwise_length_preserving() {
+        // columns, literals, casts, aliases and binary/ternary combinations are row-local.
+        assert!(is_elementwise_length_preserving(&(col("") * lit(2i32))));
+        assert!(is_elementwise_length_preserving(
+            &col("").cast(DataType::Float64)
+        ));
+        // aggregations cross list boundaries and must keep using the per-row fallback.
+        assert!(!is_elementwise_length_preserving(&col("").sum()));
+    }
+
+    #[test]
+    fn test_eval_fast_path_preserves_nulls_and_offsets() {
+        let a = Series::new("", &[1i32, 2, 3]);
+        let b = Series::new("", &[4i32, 5, 6]);
+        let list = Series::new("a", &[Some(a), None, Some(b)]);
+        let df = DataFrame::new(vec![list]).unwrap();
+
+        let out = df
+            .lazy()
+            .select([col("a")
+                .list()
+                .eval(col("") * lit(2i32), false, ListEvalErrors::Raise)])
+            .collect()
+            .unwrap();
+
+        let out = out.column("a").unwrap().list().unwrap();
+        assert_eq!(out.len(), 3);
+        assert!(out.get(1).is_none());
+        assert_eq!(out.get(0).unwrap(), Series::new("", &[2i32, 4, 6]));
+        assert_eq!(out.get(2).unwrap(), Series::new("", &[8i32, 10, 12]));
+    }
+
+    #[test]
+    fn test_ensure_same_outer_length() {
+        let a = Series::new("", &[Some(Series::new("", &[1i32])), Some(Series::new("", &[2i32]))]);
+        let b = Series::new("", &[Some(Series::new("", &[1i32]))]);
+
+        let a = a.list().unwrap();
+        let b = b.list().unwrap();
+        assert!(ensure_same_outer_length(&[a, a]).is_ok());
+        assert!(ensure_same_outer_length(&[a, b]).is_err());
+    }
+
+    #[test]
+    fn test_eval_many_elementwise_arithmetic() {
+        let a = Series::new("a", &[Some(Series::new("", &[1i32, 2])), Some(Series::new("", &[3i32]))]);
+        let b = Series::new("b", &[Some(Series::new("", &[10i32, 20])), Some(Series::new("", &[30i32]))]);
+        let df = DataFrame::new(vec![a, b]).unwrap();
+
+        let out = df
+            .lazy()
+            .select([col("a")
+                .list()
+                .eval_many(vec![col("b")], element(0) * element(1), false)])
+            .collect()
+            .unwrap();
+
+        let out = out.column("a").unwrap().list().unwrap();
+        assert_eq!(out.get(0).unwrap(), Series::new("", &[10i32, 40]));
+        assert_eq!(out.get(1).unwrap(), Series::new("", &[90i32]));
+    }
+
+    #[test]
+    fn test_eval_many_row_length_mismatch_errors() {
+        let a = Series::new("a", &[Some(Series::new("", &[1i32, 2]))]);
+        let b = Series::new("b", &[Some(Series::new("", &[1i32]))]);
+        let df = DataFrame::new(vec![a, b]).unwrap();
+
+        let out = df
+            .lazy()
+            .select([col("a")
+                .list()
+                .eval_many(vec![col("b")], element(0) + element(1), false)])
+            .collect();
+
+        assert!(out.is_err());
+    }
+
+    #[test]
+    fn test_list_eval_errors_handle() {
+        let err = || PolarsError::ComputeError("boom".into());
+
+        assert!(ListEvalErrors::Raise.handle(0, err()).is_some());
+        assert!(ListEvalErrors::NullOnError.handle(0, err()).is_none());
+        assert!(ListEvalErrors::NullOnErrorAnnotated.handle(0, err()).is_none());
+    }
+
+    /// An expression that raises a genuine `ComputeError` on rows containing `bad`, and passes
+    /// through unchanged otherwise. Used instead of `cast`, whose default (non-strict) behavior
+    /// coerces unparsable values to `null` rather than raising — which wouldn't exercise
+    /// `ListEvalErrors` at all.
+    fn fail_on_value(bad: &'static str) -> Expr {
+        col("").map(
+            move |s: Series| {
+                let ca = s.utf8()?;
+                if ca.into_iter().any(|v| v == Some(bad)) {
+                    return Err(PolarsError::ComputeError(
+                        format!("found disallowed value '{bad}'").into(),
+                    ));
+                }
+                Ok(s)
+            },
+            GetOutput::same_type(),
+        )
+    }
+
+    #[test]
+    fn test_eval_raise_aborts_on_error() {
+        // row 1 raises a genuine error; `Raise` must abort the whole `eval`.
+        let list = Series::new(
+            "a",
+            &[
+                Some(Series::new("", &["1"])),
+                Some(Series::new("", &["oops"])),
+                Some(Series::new("", &["3"])),
+            ],
+        );
+        let df = DataFrame::new(vec![list]).unwrap();
+
+        let out = df
+            .lazy()
+            .select([col("a")
+                .list()
+                .eval(fail_on_value("oops"), false, ListEvalErrors::Raise)])
+            .collect();
+
+        assert!(out.is_err());
+    }
+
+    #[test]
+    fn test_eval_null_on_error_keeps_other_rows() {
+        let list = Series::new(
+            "a",
+            &[
+                Some(Series::new("", &["1"])),
+                Some(Series::new("", &["oops"])),
+                Some(Series::new("", &["3"])),
+            ],
+        );
+        let df = DataFrame::new(vec![list]).unwrap();
+
+        let out = df
+            .lazy()
+            .select([col("a")
+                .list()
+                .eval(fail_on_value("oops"), false, ListEvalErrors::NullOnError)])
+            .collect()
+            .unwrap();
+
+        let out = out.column("a").unwrap().list().unwrap();
+        assert_eq!(out.get(0).unwrap(), Series::new("", &["1"]));
+        assert!(out.get(1).is_none());
+        assert_eq!(out.get(2).unwrap(), Series::new("", &["3"]));
+    }
+}